@@ -0,0 +1,35 @@
+/// Number of addressable bytes in the default `RamMemory` backing store.
+pub const RAM_SIZE: usize = 4096;
+
+/// Abstracts the address space a `Cpu` reads opcodes and data from.
+///
+/// Implementing this trait lets a host plug in memory-mapped I/O,
+/// instrumentation, or an alternate address space size (e.g. SUPER-CHIP's
+/// larger memory) without touching the CPU core.
+pub trait Bus {
+    fn read_byte(&self, addr: u16) -> u8;
+    fn write_byte(&mut self, addr: u16, val: u8);
+}
+
+/// Plain 4 KB RAM backing store, equivalent to the original CHIP-8 address space.
+pub struct RamMemory {
+    bytes: [u8; RAM_SIZE],
+}
+
+impl Default for RamMemory {
+    fn default() -> Self {
+        RamMemory {
+            bytes: [0; RAM_SIZE],
+        }
+    }
+}
+
+impl Bus for RamMemory {
+    fn read_byte(&self, addr: u16) -> u8 {
+        self.bytes[addr as usize]
+    }
+
+    fn write_byte(&mut self, addr: u16, val: u8) {
+        self.bytes[addr as usize] = val;
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+
+/// A decoded CHIP-8 instruction, produced by `decode` without executing it.
+///
+/// Factoring decoding out of the execution loop lets a host render
+/// mnemonics (`disassemble`) or single-step a ROM (`Cpu::step`) and inspect
+/// what is about to run before committing to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// `0x0000`, this crate's sentinel for halting `run`; executing it does nothing.
+    Halt,
+    /// `00E0`
+    ClearScreen,
+    /// `00EE`
+    Return,
+    /// `2NNN`
+    Call(u16),
+    /// `8XY0`
+    Assign(u8, u8),
+    /// `8XY1`
+    Or(u8, u8),
+    /// `8XY2`
+    And(u8, u8),
+    /// `8XY3`
+    Xor(u8, u8),
+    /// `8XY4`
+    Add(u8, u8),
+    /// `8XY5`
+    Sub(u8, u8),
+    /// `8XY6`
+    ShiftRight(u8, u8),
+    /// `8XY7`
+    SubN(u8, u8),
+    /// `8XYE`
+    ShiftLeft(u8, u8),
+    /// `ANNN`
+    SetI(u16),
+    /// `DXYN`
+    Draw(u8, u8, u8),
+    /// `EX9E`
+    SkipIfKeyPressed(u8),
+    /// `EXA1`
+    SkipIfKeyNotPressed(u8),
+    /// `FX07`
+    GetDelayTimer(u8),
+    /// `FX0A`
+    WaitForKey(u8),
+    /// `FX15`
+    SetDelayTimer(u8),
+    /// `FX18`
+    SetSoundTimer(u8),
+    /// `FX1E`
+    AddToI(u8),
+    /// `FX29`
+    SetIToFont(u8),
+    /// `FX33`
+    StoreBcd(u8),
+    /// `FX55`
+    StoreRegisters(u8),
+    /// `FX65`
+    LoadRegisters(u8),
+    /// An opcode this interpreter does not (yet) implement.
+    Unknown(u16),
+}
+
+impl Instruction {
+    /// Decodes a raw 16-bit opcode into an `Instruction`, without executing it.
+    pub fn decode(opcode: u16) -> Instruction {
+        let x = ((opcode & 0x0F00) >> 8) as u8;
+        let y = ((opcode & 0x00F0) >> 4) as u8;
+        let n = (opcode & 0x000F) as u8;
+        let addr = opcode & 0x0FFF;
+
+        match opcode {
+            0x0000 => Instruction::Halt,
+            0x00E0 => Instruction::ClearScreen,
+            0x00EE => Instruction::Return,
+            0x2000..=0x2FFF => Instruction::Call(addr),
+            0x8000..=0x8FFF => match n {
+                0x0 => Instruction::Assign(x, y),
+                0x1 => Instruction::Or(x, y),
+                0x2 => Instruction::And(x, y),
+                0x3 => Instruction::Xor(x, y),
+                0x4 => Instruction::Add(x, y),
+                0x5 => Instruction::Sub(x, y),
+                0x6 => Instruction::ShiftRight(x, y),
+                0x7 => Instruction::SubN(x, y),
+                0xE => Instruction::ShiftLeft(x, y),
+                _ => Instruction::Unknown(opcode),
+            },
+            0xA000..=0xAFFF => Instruction::SetI(addr),
+            0xD000..=0xDFFF => Instruction::Draw(x, y, n),
+            0xE000..=0xEFFF => match opcode & 0x00FF {
+                0x9E => Instruction::SkipIfKeyPressed(x),
+                0xA1 => Instruction::SkipIfKeyNotPressed(x),
+                _ => Instruction::Unknown(opcode),
+            },
+            0xF000..=0xFFFF => match opcode & 0x00FF {
+                0x07 => Instruction::GetDelayTimer(x),
+                0x0A => Instruction::WaitForKey(x),
+                0x15 => Instruction::SetDelayTimer(x),
+                0x18 => Instruction::SetSoundTimer(x),
+                0x1E => Instruction::AddToI(x),
+                0x29 => Instruction::SetIToFont(x),
+                0x33 => Instruction::StoreBcd(x),
+                0x55 => Instruction::StoreRegisters(x),
+                0x65 => Instruction::LoadRegisters(x),
+                _ => Instruction::Unknown(opcode),
+            },
+            _ => Instruction::Unknown(opcode),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Instruction::Halt => write!(f, "HALT"),
+            Instruction::ClearScreen => write!(f, "CLS"),
+            Instruction::Return => write!(f, "RET"),
+            Instruction::Call(addr) => write!(f, "CALL {:#x}", addr),
+            Instruction::Assign(x, y) => write!(f, "LD V{:X}, V{:X}", x, y),
+            Instruction::Or(x, y) => write!(f, "OR V{:X}, V{:X}", x, y),
+            Instruction::And(x, y) => write!(f, "AND V{:X}, V{:X}", x, y),
+            Instruction::Xor(x, y) => write!(f, "XOR V{:X}, V{:X}", x, y),
+            Instruction::Add(x, y) => write!(f, "ADD V{:X}, V{:X}", x, y),
+            Instruction::Sub(x, y) => write!(f, "SUB V{:X}, V{:X}", x, y),
+            Instruction::ShiftRight(x, y) => write!(f, "SHR V{:X}, V{:X}", x, y),
+            Instruction::SubN(x, y) => write!(f, "SUBN V{:X}, V{:X}", x, y),
+            Instruction::ShiftLeft(x, y) => write!(f, "SHL V{:X}, V{:X}", x, y),
+            Instruction::SetI(addr) => write!(f, "LD I, {:#x}", addr),
+            Instruction::Draw(x, y, n) => write!(f, "DRW V{:X}, V{:X}, {}", x, y, n),
+            Instruction::SkipIfKeyPressed(x) => write!(f, "SKP V{:X}", x),
+            Instruction::SkipIfKeyNotPressed(x) => write!(f, "SKNP V{:X}", x),
+            Instruction::GetDelayTimer(x) => write!(f, "LD V{:X}, DT", x),
+            Instruction::WaitForKey(x) => write!(f, "LD V{:X}, K", x),
+            Instruction::SetDelayTimer(x) => write!(f, "LD DT, V{:X}", x),
+            Instruction::SetSoundTimer(x) => write!(f, "LD ST, V{:X}", x),
+            Instruction::AddToI(x) => write!(f, "ADD I, V{:X}", x),
+            Instruction::SetIToFont(x) => write!(f, "LD F, V{:X}", x),
+            Instruction::StoreBcd(x) => write!(f, "LD B, V{:X}", x),
+            Instruction::StoreRegisters(x) => write!(f, "LD [I], V{:X}", x),
+            Instruction::LoadRegisters(x) => write!(f, "LD V{:X}, [I]", x),
+            Instruction::Unknown(opcode) => write!(f, "??? {:#06x}", opcode),
+        }
+    }
+}
@@ -0,0 +1,986 @@
+mod bus;
+mod instruction;
+mod quirks;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub use bus::{Bus, RamMemory, RAM_SIZE};
+pub use instruction::Instruction;
+pub use quirks::Quirks;
+
+/// Width of the Chip-8 monochrome framebuffer, in pixels.
+pub const DISPLAY_WIDTH: usize = 64;
+
+/// Height of the Chip-8 monochrome framebuffer, in pixels.
+pub const DISPLAY_HEIGHT: usize = 32;
+
+/// Address where the built-in font set is loaded into memory.
+const FONT_SET_ADDRESS: u16 = 0x050;
+
+/// Address where ROMs are loaded and execution begins, per the original
+/// CHIP-8 convention (the first 0x200 bytes were reserved for the
+/// interpreter itself on period-accurate hardware).
+pub const PROGRAM_START: u16 = 0x200;
+
+/// The 16-character, 5-byte-per-glyph hex font (0-F) that ROMs expect to
+/// find in low memory when drawing digit sprites via `FX29`.
+#[rustfmt::skip]
+const FONT_SET: [u8; 80] = [
+    0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+    0x20, 0x60, 0x20, 0x20, 0x70, // 1
+    0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+    0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+    0x90, 0x90, 0xF0, 0x10, 0x10, // 4
+    0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+    0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+    0xF0, 0x10, 0x20, 0x40, 0x40, // 7
+    0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+    0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+    0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+    0xE0, 0x90, 0xE0, 0x90, 0xE0, // B
+    0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+    0xE0, 0x90, 0x90, 0x90, 0xE0, // D
+    0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+    0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+];
+
+/// Returned by `load_rom` when a ROM is too large to fit in the memory
+/// remaining after `PROGRAM_START`; `load_rom_file` wraps it in an
+/// `io::Error` of kind `InvalidData`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RomTooLargeError {
+    rom_size: usize,
+    capacity: usize,
+}
+
+impl std::fmt::Display for RomTooLargeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ROM is {} bytes, but only {} bytes are available after PROGRAM_START",
+            self.rom_size, self.capacity
+        )
+    }
+}
+
+impl std::error::Error for RomTooLargeError {}
+
+/// The emulated Chip-8 cpu.
+///
+/// NNN: address
+/// NN: 8-bit constant
+/// N: 4-bit constant
+/// X and Y: 4-bit register identifier
+/// PC : Program Counter
+/// I : 16bit register (For memory address) (Similar to void pointer)
+/// VN: One of the 16 available variables. N may be 0 to F (hexadecimal)
+///
+/// `Cpu` is generic over its backing `Bus` so a host can plug in
+/// memory-mapped I/O or a differently sized address space; `RamMemory`,
+/// a plain 4 KB array, is used when no other bus is specified.
+pub struct Cpu<M: Bus = RamMemory> {
+    pub registers: [u8; 16],
+    pub memory: M,
+    pub i: u16,
+    /// Monochrome framebuffer, indexed `[row][col]`, one byte (0 or 1) per pixel.
+    pub display: [[u8; DISPLAY_WIDTH]; DISPLAY_HEIGHT],
+    /// Set whenever the display changes, so a front-end knows to redraw.
+    pub request_redraw: bool,
+    /// State of the 16-key hex keypad, set by the host before each cycle.
+    pub keys: [bool; 16],
+    /// Counts down to zero at 60 Hz, driven by `tick_timers`.
+    pub delay_timer: u8,
+    /// Counts down to zero at 60 Hz, driven by `tick_timers`; a beep should
+    /// sound for as long as this is non-zero.
+    pub sound_timer: u8,
+    /// Selects which CHIP-8 interpreter convention ambiguous opcodes follow.
+    pub quirks: Quirks,
+    position_in_memory: u16,
+    stack: [u16; 16],
+    stack_pointer: usize,
+}
+
+impl Default for Cpu<RamMemory> {
+    fn default() -> Self {
+        Cpu::new(RamMemory::default())
+    }
+}
+
+impl<M: Bus> Cpu<M> {
+    /// Builds a `Cpu` backed by the given `Bus`, with the font set loaded
+    /// into low memory and the program counter at `PROGRAM_START`.
+    pub fn new(memory: M) -> Self {
+        let mut cpu = Cpu {
+            registers: [0; 16],
+            memory,
+            i: 0,
+            display: [[0; DISPLAY_WIDTH]; DISPLAY_HEIGHT],
+            request_redraw: false,
+            keys: [false; 16],
+            delay_timer: 0,
+            sound_timer: 0,
+            quirks: Quirks::default(),
+            position_in_memory: PROGRAM_START,
+            stack: [0; 16],
+            stack_pointer: 0,
+        };
+        cpu.load_font_set();
+        cpu
+    }
+
+    /// Process all opcodes until 0x0000 is reached.
+    pub fn run(&mut self) {
+        loop {
+            if self.execute_cycle() == 0x0000 {
+                return;
+            }
+        }
+    }
+
+    /// Fetches, decodes and executes exactly one opcode, advancing the
+    /// program counter, and returns the opcode that ran.
+    ///
+    /// Unlike `run`, this does not loop or halt on `0x0000`, so a host event
+    /// loop can feed key state between cycles and drive `FX0A`'s
+    /// wait-for-key semantics by simply calling this repeatedly.
+    pub fn execute_cycle(&mut self) -> u16 {
+        let opcode = self.fetch();
+        self.apply(Instruction::decode(opcode));
+        opcode
+    }
+
+    /// Fetches, decodes and executes exactly one instruction, advancing the
+    /// program counter, and returns what it ran. Lets a host build a TUI
+    /// debugger that inspects register/memory state between instructions
+    /// instead of running to completion.
+    pub fn step(&mut self) -> Instruction {
+        let opcode = self.fetch();
+        let instruction = Instruction::decode(opcode);
+        self.apply(instruction);
+        instruction
+    }
+
+    /// Renders the instruction at `addr` as a mnemonic, e.g. `ADD V0, V1`
+    /// or `CALL 0x100`, without advancing the program counter or executing it.
+    pub fn disassemble(&self, addr: usize) -> String {
+        let addr = addr as u16;
+        let op_byte1 = self.memory.read_byte(addr) as u16;
+        let op_byte2 = self.memory.read_byte(addr + 1) as u16;
+        Instruction::decode(op_byte1 << 8 | op_byte2).to_string()
+    }
+
+    /// Reads the opcode at the program counter and advances it by 2.
+    fn fetch(&mut self) -> u16 {
+        let op_byte1 = self.memory.read_byte(self.position_in_memory) as u16;
+        let op_byte2 = self.memory.read_byte(self.position_in_memory + 1) as u16;
+        self.position_in_memory += 2;
+        op_byte1 << 8 | op_byte2
+    }
+
+    /// Executes a decoded instruction.
+    fn apply(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Halt => {}
+            Instruction::ClearScreen => self.clear_screen(),
+            Instruction::Return => self.ret(),
+            Instruction::Call(addr) => self.call(addr),
+            Instruction::Assign(x, y) => self.assign(x, y),
+            Instruction::Or(x, y) => self.or_xy(x, y),
+            Instruction::And(x, y) => self.and_xy(x, y),
+            Instruction::Xor(x, y) => self.xor_xy(x, y),
+            Instruction::Add(x, y) => self.add_xy(x, y),
+            Instruction::Sub(x, y) => self.sub_xy(x, y),
+            Instruction::ShiftRight(x, y) => self.shift_right_1(x, y),
+            Instruction::SubN(x, y) => self.set_sub_xy(x, y),
+            Instruction::ShiftLeft(x, y) => self.shift_left_1(x, y),
+            Instruction::SetI(addr) => self.set_i(addr),
+            Instruction::Draw(x, y, n) => self.draw_sprite(x, y, n),
+            Instruction::SkipIfKeyPressed(x) => self.skip_if_key_pressed(x),
+            Instruction::SkipIfKeyNotPressed(x) => self.skip_if_key_not_pressed(x),
+            Instruction::GetDelayTimer(x) => self.get_delay_timer(x),
+            Instruction::WaitForKey(x) => self.wait_for_key(x),
+            Instruction::SetDelayTimer(x) => self.set_delay_timer(x),
+            Instruction::SetSoundTimer(x) => self.set_sound_timer(x),
+            Instruction::AddToI(x) => self.add_to_i(x),
+            Instruction::SetIToFont(x) => self.set_i_to_font(x),
+            Instruction::StoreBcd(x) => self.store_bcd(x),
+            Instruction::StoreRegisters(x) => self.store_registers(x),
+            Instruction::LoadRegisters(x) => self.load_registers(x),
+            Instruction::Unknown(opcode) => unimplemented!("opcode {:04x}", opcode),
+        }
+    }
+
+    /// Resets the internal state and clears all memory, then reloads the
+    /// font set into low memory. `quirks` is left as it is.
+    pub fn reset(&mut self) {
+        self.registers = [0; 16];
+        self.i = 0;
+        self.display = [[0; DISPLAY_WIDTH]; DISPLAY_HEIGHT];
+        self.request_redraw = false;
+        self.keys = [false; 16];
+        self.delay_timer = 0;
+        self.sound_timer = 0;
+        self.position_in_memory = PROGRAM_START;
+        self.stack = [0; 16];
+        self.stack_pointer = 0;
+
+        for addr in 0..RAM_SIZE as u16 {
+            self.memory.write_byte(addr, 0);
+        }
+        self.load_font_set();
+    }
+
+    /// Decrements the delay and sound timers toward zero. The host should
+    /// call this once per frame (60 Hz), independent of the instruction
+    /// rate driven by `run`/`execute_cycle`.
+    pub fn tick_timers(&mut self) {
+        self.delay_timer = self.delay_timer.saturating_sub(1);
+        self.sound_timer = self.sound_timer.saturating_sub(1);
+    }
+
+    /// Whether the sound timer is currently non-zero, i.e. a beep should sound.
+    pub fn is_sound_playing(&self) -> bool {
+        self.sound_timer > 0
+    }
+
+    /// Copies a program into memory starting at `PROGRAM_START` (0x200).
+    ///
+    /// Fails rather than panicking if `bytes` would run past the end of
+    /// memory, since ROMs may come from untrusted or corrupt files.
+    pub fn load_rom(&mut self, bytes: &[u8]) -> Result<(), RomTooLargeError> {
+        let capacity = RAM_SIZE - PROGRAM_START as usize;
+        if bytes.len() > capacity {
+            return Err(RomTooLargeError {
+                rom_size: bytes.len(),
+                capacity,
+            });
+        }
+
+        for (offset, &byte) in bytes.iter().enumerate() {
+            self.memory.write_byte(PROGRAM_START + offset as u16, byte);
+        }
+
+        Ok(())
+    }
+
+    /// Reads a `.ch8` ROM file from disk and loads it via `load_rom`.
+    pub fn load_rom_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let bytes = fs::read(path)?;
+        self.load_rom(&bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Writes the built-in hex font into low memory.
+    fn load_font_set(&mut self) {
+        for (offset, &byte) in FONT_SET.iter().enumerate() {
+            self.memory
+                .write_byte(FONT_SET_ADDRESS + offset as u16, byte);
+        }
+    }
+
+    /// Clears the framebuffer.
+    /// 0x00E0
+    fn clear_screen(&mut self) {
+        self.display = [[0; DISPLAY_WIDTH]; DISPLAY_HEIGHT];
+        self.request_redraw = true;
+    }
+
+    /// Draws an N-byte sprite read from memory starting at `I` onto the
+    /// screen at `(Vx, Vy)`, XOR-ing it onto the existing framebuffer.
+    /// VF is set to 1 if any set pixel is flipped off (collision), 0 otherwise.
+    /// Coordinates wrap modulo the screen width/height.
+    /// 0xDXYN
+    fn draw_sprite(&mut self, x: u8, y: u8, n: u8) {
+        let x_coord = self.registers[x as usize] as usize % DISPLAY_WIDTH;
+        let y_coord = self.registers[y as usize] as usize % DISPLAY_HEIGHT;
+        self.registers[0xF] = 0;
+
+        for row in 0..n as u16 {
+            let sprite_byte = self.memory.read_byte(self.i + row);
+            for col in 0..8 {
+                if sprite_byte & (0x80 >> col) == 0 {
+                    continue;
+                }
+
+                let px = (x_coord + col) % DISPLAY_WIDTH;
+                let py = (y_coord + row as usize) % DISPLAY_HEIGHT;
+
+                if self.display[py][px] == 1 {
+                    self.registers[0xF] = 1;
+                }
+                self.display[py][px] ^= 1;
+            }
+        }
+
+        self.request_redraw = true;
+    }
+
+    /// Sets I to NNN.
+    /// 0xANNN
+    fn set_i(&mut self, addr: u16) {
+        self.i = addr;
+    }
+
+    /// Adds VX to I.
+    /// 0xFX1E
+    fn add_to_i(&mut self, x: u8) {
+        self.i = self.i.wrapping_add(self.registers[x as usize] as u16);
+    }
+
+    /// Sets I to the address of the font sprite for the hex digit in VX.
+    /// 0xFX29
+    fn set_i_to_font(&mut self, x: u8) {
+        let digit = self.registers[x as usize] as u16;
+        self.i = FONT_SET_ADDRESS + digit * 5;
+    }
+
+    /// Stores the binary-coded decimal representation of VX at addresses
+    /// I, I+1 and I+2 (hundreds, tens, units).
+    /// 0xFX33
+    fn store_bcd(&mut self, x: u8) {
+        let value = self.registers[x as usize];
+        self.memory.write_byte(self.i, value / 100);
+        self.memory.write_byte(self.i + 1, (value / 10) % 10);
+        self.memory.write_byte(self.i + 2, value % 10);
+    }
+
+    /// Stores registers V0 through VX in memory starting at address I.
+    /// 0xFX55
+    fn store_registers(&mut self, x: u8) {
+        for offset in 0..=x as u16 {
+            self.memory
+                .write_byte(self.i + offset, self.registers[offset as usize]);
+        }
+        self.apply_load_store_increments_i_quirk(x);
+    }
+
+    /// Fills registers V0 through VX from memory starting at address I.
+    /// 0xFX65
+    fn load_registers(&mut self, x: u8) {
+        for offset in 0..=x as u16 {
+            self.registers[offset as usize] = self.memory.read_byte(self.i + offset);
+        }
+        self.apply_load_store_increments_i_quirk(x);
+    }
+
+    /// Advances `I` past the last register touched by `FX55`/`FX65` when
+    /// `quirks.load_store_increments_i` is set, matching the COSMAC VIP.
+    fn apply_load_store_increments_i_quirk(&mut self, x: u8) {
+        if self.quirks.load_store_increments_i {
+            self.i += x as u16 + 1;
+        }
+    }
+
+    /// Skips the next instruction if the key in VX is pressed.
+    /// 0xEX9E
+    fn skip_if_key_pressed(&mut self, x: u8) {
+        if self.key_pressed(x) {
+            self.position_in_memory += 2;
+        }
+    }
+
+    /// Skips the next instruction if the key in VX is not pressed.
+    /// 0xEXA1
+    fn skip_if_key_not_pressed(&mut self, x: u8) {
+        if !self.key_pressed(x) {
+            self.position_in_memory += 2;
+        }
+    }
+
+    /// Whether the key named by VX is currently pressed. VX is masked to
+    /// 0xF first, since it's an arbitrary register byte, not guaranteed to
+    /// already be a valid key index.
+    fn key_pressed(&self, x: u8) -> bool {
+        self.keys[(self.registers[x as usize] & 0xF) as usize]
+    }
+
+    /// Blocks until any key is pressed, storing its index in VX. Implemented
+    /// by rewinding the program counter so the same instruction is
+    /// re-executed on the next cycle until a key shows up as pressed.
+    /// 0xFX0A
+    fn wait_for_key(&mut self, x: u8) {
+        match self.keys.iter().position(|&pressed| pressed) {
+            Some(key) => self.registers[x as usize] = key as u8,
+            None => self.position_in_memory -= 2,
+        }
+    }
+
+    /// Sets VX to the value of the delay timer.
+    /// 0xFX07
+    fn get_delay_timer(&mut self, x: u8) {
+        self.registers[x as usize] = self.delay_timer;
+    }
+
+    /// Sets the delay timer to VX.
+    /// 0xFX15
+    fn set_delay_timer(&mut self, x: u8) {
+        self.delay_timer = self.registers[x as usize];
+    }
+
+    /// Sets the sound timer to VX.
+    /// 0xFX18
+    fn set_sound_timer(&mut self, x: u8) {
+        self.sound_timer = self.registers[x as usize];
+    }
+
+    /// Perform a jump and calls subroutine
+    fn call(&mut self, addr: u16) {
+        let sp = self.stack_pointer;
+        let stack = &mut self.stack;
+
+        if sp > stack.len() {
+            panic!("Stack overflow");
+        }
+
+        stack[sp] = self.position_in_memory;
+        self.stack_pointer += 1;
+        self.position_in_memory = addr;
+    }
+
+    /// Returns from subroutine
+    fn ret(&mut self) {
+        if self.stack_pointer == 0 {
+            panic!("Stack underflow");
+        }
+
+        self.stack_pointer -= 1;
+        self.position_in_memory = self.stack[self.stack_pointer];
+    }
+
+    /// Sets Vx to the value of Vy
+    /// 0x8XY0
+    fn assign(&mut self, x: u8, y: u8) {
+        self.registers[x as usize] = self.registers[y as usize];
+    }
+
+    /// Sets VX to VX or VY. (Bitwise OR operation)
+    /// 0x8XY1
+    fn or_xy(&mut self, x: u8, y: u8) {
+        self.registers[x as usize] |= self.registers[y as usize];
+        self.apply_reset_vf_on_logic_quirk();
+    }
+
+    /// Sets VX to VX and VY. (Bitwise AND operation)
+    /// 0x8XY2
+    fn and_xy(&mut self, x: u8, y: u8) {
+        self.registers[x as usize] &= self.registers[y as usize];
+        self.apply_reset_vf_on_logic_quirk();
+    }
+
+    /// Sets VX to VX xor VY.
+    /// 0x8XY3
+    fn xor_xy(&mut self, x: u8, y: u8) {
+        self.registers[x as usize] ^= self.registers[y as usize];
+        self.apply_reset_vf_on_logic_quirk();
+    }
+
+    /// Resets VF to 0 after a logic op when `quirks.reset_vf_on_logic` is set,
+    /// matching the COSMAC VIP's bitwise logic unit side effect.
+    fn apply_reset_vf_on_logic_quirk(&mut self) {
+        if self.quirks.reset_vf_on_logic {
+            self.registers[0xF] = 0;
+        }
+    }
+
+    /// Adds VY to VX. VF is set to 1 when there's a carry, and to 0 when there isn't.
+    /// 0x8XY4
+    fn add_xy(&mut self, x: u8, y: u8) {
+        if self.registers[x as usize] > (0xFF - self.registers[y as usize]) {
+            self.registers[0xF] = 1; // carry
+        } else {
+            self.registers[0xF] = 0;
+            self.registers[x as usize] += self.registers[y as usize];
+        }
+    }
+
+    /// VY is subtracted from VX. VF is set to 0 when there's a borrow, and 1 when there isn't.
+    /// 0x8XY5
+    fn sub_xy(&mut self, x: u8, y: u8) {
+        if self.registers[x as usize] < self.registers[y as usize] {
+            self.registers[0xF] = 0; // borrow
+        } else {
+            self.registers[0xF] = 1;
+            self.registers[x as usize] -= self.registers[y as usize];
+        }
+    }
+
+    /// Shifts VX (or VY, under the `shift_uses_vy` quirk) right by 1, storing
+    /// the least significant bit shifted out in VF.
+    /// 0x8XY6
+    fn shift_right_1(&mut self, x: u8, y: u8) {
+        let value = self.registers[self.shift_source(x, y) as usize];
+        self.registers[x as usize] = value >> 1;
+        self.registers[0xF] = value & 0x1;
+    }
+
+    /// Sets VX to VY minus VX. VF is set to 0 when there's a borrow, and 1 when there isn't.
+    /// 0x8XY7
+    fn set_sub_xy(&mut self, x: u8, y: u8) {
+        if self.registers[x as usize] > self.registers[y as usize] {
+            self.registers[0xF] = 0; // borrow
+        } else {
+            self.registers[0xF] = 1;
+            self.registers[x as usize] = self.registers[y as usize] - self.registers[x as usize];
+        }
+    }
+
+    /// Shifts VX (or VY, under the `shift_uses_vy` quirk) left by 1, storing
+    /// the most significant bit shifted out in VF.
+    /// 0x8XYE
+    fn shift_left_1(&mut self, x: u8, y: u8) {
+        let value = self.registers[self.shift_source(x, y) as usize];
+        self.registers[x as usize] = value << 1;
+        self.registers[0xF] = (value & 0x80) >> 7;
+    }
+
+    /// Which register `8XY6`/`8XYE` read their operand from, per `quirks.shift_uses_vy`.
+    fn shift_source(&self, x: u8, y: u8) -> u8 {
+        if self.quirks.shift_uses_vy {
+            y
+        } else {
+            x
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A second, non-`RamMemory` `Bus`, backed by a `Vec` instead of a fixed
+    /// array, proving `Cpu<M>` works with a bus it wasn't written against.
+    struct VecMemory(Vec<u8>);
+
+    impl Bus for VecMemory {
+        fn read_byte(&self, addr: u16) -> u8 {
+            self.0[addr as usize]
+        }
+
+        fn write_byte(&mut self, addr: u16, val: u8) {
+            self.0[addr as usize] = val;
+        }
+    }
+
+    #[test]
+    fn test_cpu_is_generic_over_a_custom_bus() {
+        let mut cpu = Cpu::new(VecMemory(vec![0; RAM_SIZE]));
+        cpu.registers[0x0] = 5;
+        cpu.registers[0x1] = 10;
+
+        cpu.load_rom(&[0x80, 0x14]).unwrap();
+        cpu.run();
+
+        assert_eq!(15, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_add_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 5;
+        cpu.registers[0x1] = 10;
+
+        cpu.load_rom(&[0x80, 0x14]).unwrap();
+
+        cpu.run();
+
+        assert_eq!(15, cpu.registers[0x0]);
+        assert_eq!(0, cpu.registers[0xF]);
+        cpu.reset();
+
+        cpu.registers[0x0] = 255;
+        cpu.registers[0x1] = 1;
+
+        cpu.load_rom(&[0x80, 0x14]).unwrap();
+        cpu.run();
+
+        assert_eq!(1, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_sub_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 10;
+        cpu.registers[0x1] = 6;
+
+        cpu.load_rom(&[0x80, 0x15]).unwrap();
+        cpu.run();
+
+        assert_eq!(4, cpu.registers[0x0]);
+        assert_eq!(1, cpu.registers[0xF]);
+        cpu.reset();
+
+        cpu.registers[0x0] = 0;
+        cpu.registers[0x1] = 1;
+
+        cpu.load_rom(&[0x80, 0x15]).unwrap();
+        cpu.run();
+
+        assert_eq!(0, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 6;
+        cpu.registers[0x1] = 10;
+
+        cpu.load_rom(&[0x80, 0x10]).unwrap();
+
+        cpu.run();
+
+        assert_eq!(10, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_or_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0b1100;
+        cpu.registers[0x1] = 0b0011;
+
+        cpu.load_rom(&[0x80, 0x11]).unwrap();
+        cpu.run();
+
+        assert_eq!(0b1111, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_and_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0xFF;
+        cpu.registers[0x1] = 0x0F;
+
+        cpu.load_rom(&[0x80, 0x12]).unwrap();
+        cpu.run();
+
+        assert_eq!(0x0F, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_xor_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0x11;
+        cpu.registers[0x1] = 0xFF;
+
+        cpu.load_rom(&[0x80, 0x13]).unwrap();
+        cpu.run();
+
+        assert_eq!(0xEE, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_shift_right_1() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0b11;
+
+        cpu.load_rom(&[0x80, 0x16]).unwrap();
+        cpu.run();
+
+        assert_eq!(0x1, cpu.registers[0xF]);
+        assert_eq!(0b01, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_set_sub_xy() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 6;
+        cpu.registers[0x1] = 10;
+
+        cpu.load_rom(&[0x80, 0x17]).unwrap();
+        cpu.run();
+
+        assert_eq!(4, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_shift_left_1() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0b11;
+
+        cpu.load_rom(&[0x80, 0x0E]).unwrap();
+        cpu.run();
+
+        assert_eq!(0b110, cpu.registers[0x0]);
+        assert_eq!(0, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_shift_left_1_sets_vf_from_msb() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0b1000_0001;
+
+        cpu.load_rom(&[0x80, 0x0E]).unwrap();
+        cpu.run();
+
+        assert_eq!(0b0000_0010, cpu.registers[0x0]);
+        assert_eq!(1, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_shift_right_1_uses_vy_under_quirk() {
+        let mut cpu = Cpu::default();
+        cpu.quirks.shift_uses_vy = true;
+        cpu.registers[0x0] = 0xFF;
+        cpu.registers[0x1] = 0b10;
+
+        cpu.load_rom(&[0x80, 0x16]).unwrap();
+        cpu.run();
+
+        assert_eq!(0b1, cpu.registers[0x0]);
+        assert_eq!(0, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_skip_if_key_pressed_masks_out_of_range_register() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0x20;
+        cpu.keys[0x0] = true;
+
+        cpu.load_rom(&[0xE0, 0x9E]).unwrap();
+
+        cpu.run();
+    }
+
+    #[test]
+    fn test_call_and_ret() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 5;
+        cpu.registers[0x1] = 10;
+
+        cpu.load_rom(&[0x21, 0x00, 0x21, 0x00]).unwrap();
+
+        cpu.memory.write_byte(0x100, 0x80);
+        cpu.memory.write_byte(0x101, 0x14);
+        cpu.memory.write_byte(0x102, 0x00);
+        cpu.memory.write_byte(0x103, 0xEE);
+
+        cpu.run();
+
+        assert_eq!(25, cpu.registers[0x0]);
+    }
+
+    #[test]
+    fn test_set_i_and_add_to_i() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 5;
+
+        cpu.load_rom(&[0xA2, 0x00, 0xF0, 0x1E]).unwrap();
+        cpu.run();
+
+        assert_eq!(0x205, cpu.i);
+    }
+
+    #[test]
+    fn test_set_i_to_font() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0xA;
+
+        cpu.load_rom(&[0xF0, 0x29]).unwrap();
+        cpu.run();
+
+        assert_eq!(FONT_SET_ADDRESS + 0xA * 5, cpu.i);
+    }
+
+    #[test]
+    fn test_store_bcd() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 234;
+        cpu.i = 0x300;
+
+        cpu.load_rom(&[0xF0, 0x33]).unwrap();
+        cpu.run();
+
+        assert_eq!(2, cpu.memory.read_byte(0x300));
+        assert_eq!(3, cpu.memory.read_byte(0x301));
+        assert_eq!(4, cpu.memory.read_byte(0x302));
+    }
+
+    #[test]
+    fn test_store_and_load_registers() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 1;
+        cpu.registers[0x1] = 2;
+        cpu.i = 0x300;
+
+        cpu.load_rom(&[0xF1, 0x55]).unwrap();
+        cpu.run();
+
+        assert_eq!(1, cpu.memory.read_byte(0x300));
+        assert_eq!(2, cpu.memory.read_byte(0x301));
+
+        cpu.reset();
+        cpu.i = 0x300;
+        cpu.memory.write_byte(0x300, 1);
+        cpu.memory.write_byte(0x301, 2);
+
+        cpu.load_rom(&[0xF1, 0x65]).unwrap();
+        cpu.run();
+
+        assert_eq!(1, cpu.registers[0x0]);
+        assert_eq!(2, cpu.registers[0x1]);
+    }
+
+    #[test]
+    fn test_skip_if_key_not_pressed() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 0x1;
+        cpu.registers[0x1] = 0;
+
+        // EXA1 skips ADD V1, 1 since key 1 is not pressed.
+        cpu.load_rom(&[0xE0, 0xA1, 0x71, 0x01]).unwrap();
+        cpu.step();
+        cpu.step();
+
+        assert_eq!(0, cpu.registers[0x1]);
+    }
+
+    #[test]
+    fn test_wait_for_key_blocks_until_a_key_is_pressed() {
+        let mut cpu = Cpu::default();
+
+        cpu.load_rom(&[0xF0, 0x0A]).unwrap();
+        cpu.step();
+
+        // No key pressed yet: the instruction re-runs in place.
+        assert_eq!(PROGRAM_START, cpu.position_in_memory);
+
+        cpu.keys[0x7] = true;
+        cpu.step();
+
+        assert_eq!(0x7, cpu.registers[0x0]);
+        assert_eq!(PROGRAM_START + 2, cpu.position_in_memory);
+    }
+
+    #[test]
+    fn test_set_and_get_delay_timer() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 10;
+
+        // FX15 sets DT from V0, then FX07 reads it back into V1.
+        cpu.load_rom(&[0xF0, 0x15, 0xF1, 0x07]).unwrap();
+        cpu.step();
+        cpu.step();
+
+        assert_eq!(10, cpu.registers[0x1]);
+    }
+
+    #[test]
+    fn test_set_sound_timer_and_is_sound_playing() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 2;
+
+        cpu.load_rom(&[0xF0, 0x18]).unwrap();
+        cpu.step();
+
+        assert!(cpu.is_sound_playing());
+    }
+
+    #[test]
+    fn test_tick_timers_saturates_at_zero() {
+        let mut cpu = Cpu {
+            delay_timer: 1,
+            ..Cpu::default()
+        };
+
+        cpu.tick_timers();
+        assert_eq!(0, cpu.delay_timer);
+        assert!(!cpu.is_sound_playing());
+
+        cpu.tick_timers();
+        assert_eq!(0, cpu.delay_timer);
+    }
+
+    #[test]
+    fn test_font_set_is_loaded_at_font_set_address() {
+        let cpu = Cpu::default();
+
+        assert_eq!(0xF0, cpu.memory.read_byte(FONT_SET_ADDRESS));
+        assert_eq!(0xF0, cpu.memory.read_byte(FONT_SET_ADDRESS + 75)); // 'F', first byte
+    }
+
+    #[test]
+    fn test_draw_sprite_sets_vf_on_collision_and_xors_pixels() {
+        // the '0' glyph, 0xF0 0x90 0x90 0x90 0xF0
+        let mut cpu = Cpu {
+            i: FONT_SET_ADDRESS,
+            ..Cpu::default()
+        };
+
+        // DXYN twice: first draw has no collision, second XORs it back off.
+        cpu.load_rom(&[0xD0, 0x15, 0xD0, 0x15]).unwrap();
+        cpu.step();
+
+        assert_eq!(1, cpu.display[0][0]);
+        assert_eq!(0, cpu.registers[0xF]);
+        assert!(cpu.request_redraw);
+
+        cpu.step();
+
+        assert_eq!(0, cpu.display[0][0]);
+        assert_eq!(1, cpu.registers[0xF]);
+    }
+
+    #[test]
+    fn test_draw_sprite_wraps_coordinates() {
+        let mut cpu = Cpu {
+            i: FONT_SET_ADDRESS,
+            ..Cpu::default()
+        };
+        cpu.registers[0x0] = (DISPLAY_WIDTH - 1) as u8;
+        cpu.registers[0x1] = (DISPLAY_HEIGHT - 1) as u8;
+
+        cpu.load_rom(&[0xD0, 0x15]).unwrap();
+        cpu.step();
+
+        assert_eq!(1, cpu.display[DISPLAY_HEIGHT - 1][DISPLAY_WIDTH - 1]);
+    }
+
+    #[test]
+    fn test_clear_screen() {
+        let mut cpu = Cpu::default();
+        cpu.display[0][0] = 1;
+        cpu.request_redraw = false;
+
+        cpu.load_rom(&[0x00, 0xE0]).unwrap();
+        cpu.run();
+
+        assert_eq!([[0; DISPLAY_WIDTH]; DISPLAY_HEIGHT], cpu.display);
+        assert!(cpu.request_redraw);
+    }
+
+    #[test]
+    fn test_load_rom_rejects_a_rom_too_large_to_fit_in_memory() {
+        let mut cpu = Cpu::default();
+        let oversized_rom = vec![0u8; RAM_SIZE - PROGRAM_START as usize + 1];
+
+        assert_eq!(
+            Err(RomTooLargeError {
+                rom_size: oversized_rom.len(),
+                capacity: RAM_SIZE - PROGRAM_START as usize,
+            }),
+            cpu.load_rom(&oversized_rom)
+        );
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let mut cpu = Cpu::default();
+        cpu.load_rom(&[0x80, 0x14]).unwrap();
+
+        assert_eq!("ADD V0, V1", cpu.disassemble(PROGRAM_START as usize));
+    }
+
+    #[test]
+    fn test_step_returns_the_executed_instruction() {
+        let mut cpu = Cpu::default();
+        cpu.registers[0x0] = 5;
+        cpu.registers[0x1] = 10;
+        cpu.load_rom(&[0x80, 0x14]).unwrap();
+
+        let instruction = cpu.step();
+
+        assert_eq!(Instruction::Add(0x0, 0x1), instruction);
+        assert_eq!(15, cpu.registers[0x0]);
+    }
+}
@@ -0,0 +1,33 @@
+/// Selects between the handful of CHIP-8 instruction conventions that drifted
+/// apart between the original COSMAC VIP interpreter and later derivatives
+/// (SUPER-CHIP, modern interpreters). Test ROMs and real games disagree on
+/// which convention they expect, so `Cpu` exposes this as a field the host
+/// can set before running a ROM rather than picking one behavior forever.
+pub struct Quirks {
+    /// `8XY6`/`8XYE` shift `VY` into `VX` before shifting, as the COSMAC VIP
+    /// did, instead of shifting `VX` in place. Most CHIP-8 ROMs written
+    /// before SUPER-CHIP (e.g. the original `IBM Logo`, VIP-era games)
+    /// expect `true`; SUPER-CHIP and most modern ROMs expect `false`.
+    pub shift_uses_vy: bool,
+    /// `FX55`/`FX65` leave `I` advanced past the last register touched
+    /// (`I += X + 1`), matching the COSMAC VIP. Many original-era ROMs rely
+    /// on this side effect to walk memory across successive saves/loads;
+    /// SUPER-CHIP and most modern ROMs expect `I` to be left unchanged.
+    pub load_store_increments_i: bool,
+    /// `8XY1`/`8XY2`/`8XY3` (OR/AND/XOR) reset `VF` to 0 after the
+    /// operation, as the COSMAC VIP did as a side effect of its bitwise
+    /// logic unit. Most modern ROMs expect `VF` to be left untouched.
+    pub reset_vf_on_logic: bool,
+}
+
+impl Default for Quirks {
+    /// Defaults to the modern/SUPER-CHIP convention, matching this crate's
+    /// historical behavior.
+    fn default() -> Self {
+        Quirks {
+            shift_uses_vy: false,
+            load_store_increments_i: false,
+            reset_vf_on_logic: false,
+        }
+    }
+}
@@ -1,6 +1,4 @@
-mod cpu;
-
-use cpu::Cpu;
+use chip_8::cpu::Cpu;
 
 fn main() {
     let mut cpu = Cpu::default();
@@ -8,8 +6,7 @@ fn main() {
     cpu.registers[0] = 255;
     cpu.registers[1] = 1;
 
-    cpu.memory[0x000] = 0x80;
-    cpu.memory[0x001] = 0x14;
+    cpu.load_rom(&[0x80, 0x14]).unwrap();
     cpu.run();
 
     println!("carry flag = {}", cpu.registers[0xF]);